@@ -21,32 +21,226 @@ enum VoteError {
     NoSuchProposal,
     AccessRejected,
     UpdateError,
+    VotingClosed,
+    ActionTooLarge,
+    NotAuthorized,
 }
 
-#[derive(Debug, CandidType, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, CandidType, Deserialize)]
+enum ProposalOutcome {
+    Pending,
+    Accepted,
+    Rejected,
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, CandidType, Deserialize)]
+enum Role {
+    Admin,
+    Council,
+    Member,
+}
+
+impl Storable for Role {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+impl BoundedStorable for Role {
+    const MAX_SIZE: u32 = 50;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+/// Newtype so `candid::Principal` (which implements neither `Storable` nor
+/// `BoundedStorable` itself) can be used as a `StableBTreeMap` key. Bound is
+/// the IC's own max principal length, so every valid principal fits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct PrincipalKey(candid::Principal);
+
+impl Storable for PrincipalKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(self.0.as_slice().to_vec())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        PrincipalKey(candid::Principal::from_slice(bytes.as_ref()))
+    }
+}
+
+impl BoundedStorable for PrincipalKey {
+    const MAX_SIZE: u32 = 29;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+impl From<candid::Principal> for PrincipalKey {
+    fn from(principal: candid::Principal) -> Self {
+        PrincipalKey(principal)
+    }
+}
+
+/// An inter-canister call to dispatch once a proposal is `Accepted`, stored
+/// with its Candid-encoded argument bytes (the "preimage" of the call).
+#[derive(Debug, Clone, CandidType, Deserialize)]
+struct ProposalAction {
+    target: candid::Principal,
+    method: String,
+    arg: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Default, CandidType, Deserialize)]
+struct ProposalExecution {
+    executed: bool,
+    /// Set synchronously while a `call_raw` for this proposal's action is
+    /// in flight, so a concurrent trigger (another voter's `vote` crossing
+    /// `Accepted`, the heartbeat, or a manual `execute_proposal`) can't
+    /// dispatch the same action a second time before the first call
+    /// resolves.
+    in_flight: bool,
+    err: Option<String>,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize)]
 struct Proposal {
     description: String,
-    approve: i32,
-    reject: i32,
-    pass: i32,
+    approve: u64,
+    reject: u64,
+    pass: u64,
     is_active: bool,
-    voted: Vec<candid::Principal>,
+    voted: Vec<(candid::Principal, u64)>,
     owner: candid::Principal,
+    created_at_ns: u64,
+    voting_period_ns: u64,
+    quorum: u64,
+    approval_threshold_bps: u16,
+    outcome: ProposalOutcome,
+    action: Option<ProposalAction>,
+    execution: ProposalExecution,
+}
+
+impl Proposal {
+    /// Wall-clock timestamp (ns) after which the voting window is closed.
+    fn deadline(&self) -> u64 {
+        self.created_at_ns.saturating_add(self.voting_period_ns)
+    }
+
+    /// Re-evaluates `outcome` from the current tallies against `quorum` and
+    /// `approval_threshold_bps`. Called after every vote and when a
+    /// proposal ends, so `outcome` always reflects the latest tallies.
+    fn evaluate_outcome(&self) -> ProposalOutcome {
+        let total = self.approve + self.reject + self.pass;
+        if total < self.quorum {
+            return ProposalOutcome::Failed;
+        }
+
+        let decided = self.approve + self.reject;
+        if decided == 0 {
+            return ProposalOutcome::Failed;
+        }
+
+        let approve_bps = (self.approve * 10_000) / decided;
+        if approve_bps >= self.approval_threshold_bps as u64 {
+            ProposalOutcome::Accepted
+        } else {
+            ProposalOutcome::Rejected
+        }
+    }
 }
 
 #[derive(Debug, CandidType, Deserialize)]
 struct CreateProposal {
     description: String,
     is_active: bool,
+    voting_period_ns: u64,
+    quorum: u64,
+    approval_threshold_bps: u16,
+    action: Option<ProposalAction>,
+}
+
+/// Schema version of the current `Proposal` layout. Bump this and add a
+/// migration step in `Proposal::from_bytes` whenever a field is added,
+/// removed, or reinterpreted.
+const CURRENT_PROPOSAL_VERSION: u16 = 1;
+
+/// Envelope `Proposal` is actually serialized as, so stable-memory entries
+/// carry their own schema version instead of being bare, unversioned bytes.
+#[derive(Debug, Clone, CandidType, Deserialize)]
+struct StorableProposal {
+    version: u16,
+    inner: Proposal,
+}
+
+/// The wire format `Proposal` was stored in before chunk0-1 added voting
+/// deadlines (and later commits added weighting, quorum, and execution).
+/// Kept only so `Proposal::from_bytes` can up-convert stable memory written
+/// by that baseline instead of panicking on missing fields.
+#[derive(Debug, Clone, CandidType, Deserialize)]
+struct ProposalV1 {
+    description: String,
+    approve: i32,
+    reject: i32,
+    pass: i32,
+    is_active: bool,
+    voted: Vec<candid::Principal>,
+    owner: candid::Principal,
+}
+
+impl From<ProposalV1> for Proposal {
+    fn from(old: ProposalV1) -> Self {
+        Proposal {
+            description: old.description,
+            approve: old.approve.max(0) as u64,
+            reject: old.reject.max(0) as u64,
+            pass: old.pass.max(0) as u64,
+            is_active: old.is_active,
+            // The pre-weighting model was one-principal-one-vote; carry
+            // each voter over with a weight of 1.
+            voted: old.voted.into_iter().map(|principal| (principal, 1)).collect(),
+            owner: old.owner,
+            // No deadline existed yet; leave the voting window open rather
+            // than retroactively expiring proposals the heartbeat never
+            // would have closed.
+            created_at_ns: 0,
+            voting_period_ns: u64::MAX,
+            // No quorum/threshold existed yet; preserve a simple majority
+            // with no minimum participation so `evaluate_outcome` reproduces
+            // the old "just tally" behavior until the proposal is edited.
+            quorum: 0,
+            approval_threshold_bps: 5_000,
+            outcome: ProposalOutcome::Pending,
+            action: None,
+            execution: ProposalExecution::default(),
+        }
+    }
 }
 
 impl Storable for Proposal {
     fn to_bytes(&self) -> Cow<[u8]> {
-        Cow::Owned(Encode!(self).unwrap())
+        let envelope = StorableProposal {
+            version: CURRENT_PROPOSAL_VERSION,
+            inner: self.clone(),
+        };
+        Cow::Owned(Encode!(&envelope).unwrap())
     }
 
     fn from_bytes(bytes: Cow<[u8]>) -> Self {
-        Decode!(bytes.as_ref(), Self).unwrap()
+        // Newest layout: a versioned envelope.
+        if let Ok(envelope) = Decode!(bytes.as_ref(), StorableProposal) {
+            return envelope.inner;
+        }
+
+        // Next: a bare `Proposal` with no envelope, from after chunk0-1 but
+        // before chunk0-5 introduced the envelope.
+        if let Ok(proposal) = Decode!(bytes.as_ref(), Proposal) {
+            return proposal;
+        }
+
+        // Oldest: the pre-chunk0-1 baseline shape, up-converted in memory.
+        Decode!(bytes.as_ref(), ProposalV1).unwrap().into()
     }
 }
 
@@ -59,12 +253,83 @@ thread_local! {
     static MEMORY_MANAGER: RefCell<MemoryManager<DefaultMemoryImpl>> =
         RefCell::new(MemoryManager::init(DefaultMemoryImpl::default()));
     static PROPOSAL_MAP: RefCell<StableBTreeMap<u64, Proposal, Memory>> =
-        RefCell::new(StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(@)))));
+        RefCell::new(StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(0)))));
+    static VOTING_POWER_MAP: RefCell<StableBTreeMap<PrincipalKey, u64, Memory>> =
+        RefCell::new(StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(1)))));
+    static SCHEMA_VERSION_MAP: RefCell<StableBTreeMap<u8, u16, Memory>> =
+        RefCell::new(StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(2)))));
+    static MEMBERS_MAP: RefCell<StableBTreeMap<PrincipalKey, Role, Memory>> =
+        RefCell::new(StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(3)))));
+}
+
+/// Returns the caller's role, or `None` if they aren't a registered member.
+fn role_of(principal: &candid::Principal) -> Option<Role> {
+    MEMBERS_MAP.with(|m| m.borrow().get(&PrincipalKey::from(*principal)))
+}
+
+/// Returns `Ok(())` if the caller has one of `allowed`, `Err(NotAuthorized)`
+/// otherwise.
+fn require_role(caller: candid::Principal, allowed: &[Role]) -> Result<(), VoteError> {
+    match role_of(&caller) {
+        Some(role) if allowed.contains(&role) => Ok(()),
+        _ => Err(VoteError::NotAuthorized),
+    }
+}
+
+/// `SCHEMA_VERSION_MAP` only ever stores one row, recording the schema
+/// version the canister was last upgraded against.
+const SCHEMA_VERSION_KEY: u8 = 0;
+
+/// The default voting power a principal has until an admin registers an
+/// explicit weight for it.
+const DEFAULT_VOTING_POWER: u64 = 1;
+
+#[ic_cdk::init]
+fn init() {
+    // Whoever installs the canister starts out as its sole admin.
+    MEMBERS_MAP.with(|m| m.borrow_mut().insert(PrincipalKey::from(ic_cdk::caller()), Role::Admin));
+}
+
+/// Rewrites every stored proposal through the current `Storable` envelope
+/// (decoding whatever layout it was written in and re-encoding it as
+/// `CURRENT_PROPOSAL_VERSION`), so an upgrade can never leave entries
+/// stranded on a layout later code can't read.
+#[ic_cdk::post_upgrade]
+fn post_upgrade() {
+    let keys: Vec<u64> = PROPOSAL_MAP.with(|p| p.borrow().iter().map(|(key, _)| key).collect());
+
+    PROPOSAL_MAP.with(|p| {
+        let mut p = p.borrow_mut();
+        for key in keys {
+            if let Some(proposal) = p.get(&key) {
+                p.insert(key, proposal);
+            }
+        }
+    });
+
+    SCHEMA_VERSION_MAP.with(|m| {
+        m.borrow_mut()
+            .insert(SCHEMA_VERSION_KEY, CURRENT_PROPOSAL_VERSION)
+    });
+
+    // `MEMBERS_MAP` is stable-memory-backed, so admin status survives
+    // upgrades on its own. But `init()` only runs on a fresh install, so a
+    // canister upgrading straight from a build that predates the
+    // membership registry (which gated admin-only calls on a plain
+    // thread_local, reset to `anonymous()` by every upgrade) would land
+    // here with no admin at all and nobody able to call `add_member`.
+    // Seed the upgrade caller (the controller performing the upgrade) as
+    // admin whenever the registry has none, so the canister can never be
+    // left without one.
+    let has_admin = MEMBERS_MAP.with(|m| m.borrow().iter().any(|(_, role)| role == Role::Admin));
+    if !has_admin {
+        MEMBERS_MAP.with(|m| m.borrow_mut().insert(PrincipalKey::from(ic_cdk::caller()), Role::Admin));
+    }
 }
 
 #[ic_cdk::query]
 fn get_proposal(key: u64) -> Option<Proposal> {
-    PROPOSAL_MAP.with(|p| p.borrow().get(&key).cloned())
+    PROPOSAL_MAP.with(|p| p.borrow().get(&key))
 }
 
 #[ic_cdk::query]
@@ -73,7 +338,9 @@ fn get_proposal_count() -> u64 {
 }
 
 #[ic_cdk::update]
-fn create_proposal(key: i64, proposal: CreateProposal) -> Option<Proposal> {
+fn create_proposal(key: u64, proposal: CreateProposal) -> Result<(), VoteError> {
+    require_role(ic_cdk::caller(), &[Role::Council, Role::Admin])?;
+
     let value: Proposal = Proposal {
         description: proposal.description,
         approve: 0,
@@ -82,9 +349,23 @@ fn create_proposal(key: i64, proposal: CreateProposal) -> Option<Proposal> {
         is_active: proposal.is_active,
         voted: Vec::new(),
         owner: ic_cdk::caller(),
+        created_at_ns: ic_cdk::api::time(),
+        voting_period_ns: proposal.voting_period_ns,
+        quorum: proposal.quorum,
+        approval_threshold_bps: proposal.approval_threshold_bps,
+        outcome: ProposalOutcome::Pending,
+        action: proposal.action,
+        execution: ProposalExecution::default(),
     };
 
-    PROPOSAL_MAP.with(|p: &RefCell<BTreeMap<u64, Proposal, _>>| p.borrow_mut().insert(key, value))
+    // A proposal carrying an oversize action would never fit in the
+    // BoundedStorable slot it's about to be written to
+    if value.to_bytes().len() as u32 > MAX_VALUE_SIZE {
+        return Err(VoteError::ActionTooLarge);
+    }
+
+    PROPOSAL_MAP.with(|p| p.borrow_mut().insert(key, value));
+    Ok(())
 }
 
 #[ic_cdk::update]
@@ -112,8 +393,19 @@ fn edit_proposal(key: u64, proposal: CreateProposal) -> Result<(), VoteError> {
             is_active: proposal.is_active,
             voted: old_proposal.voted,
             owner: old_proposal.owner,
+            created_at_ns: old_proposal.created_at_ns,
+            voting_period_ns: proposal.voting_period_ns,
+            quorum: proposal.quorum,
+            approval_threshold_bps: proposal.approval_threshold_bps,
+            outcome: old_proposal.outcome,
+            action: proposal.action,
+            execution: old_proposal.execution,
         };
 
+        if value.to_bytes().len() as u32 > MAX_VALUE_SIZE {
+            return Err(VoteError::ActionTooLarge);
+        }
+
         // Insert the updated proposal into the map and handle the result
         match p.insert(key, value) {
             Some(_) => Ok(()),
@@ -124,64 +416,248 @@ fn edit_proposal(key: u64, proposal: CreateProposal) -> Result<(), VoteError> {
 
 #[ic_cdk::update]
 fn end_proposal(key: u64) -> Result<(), VoteError> {
-    PROPOSAL_MAP.with(|p| {
+    let (result, became_accepted) = PROPOSAL_MAP.with(|p| {
         let mut p = p.borrow_mut();
 
         // Retrieve old proposal or return NoSuchProposal error
-        let old_proposal = match p.get(&key) {
+        let mut old_proposal = match p.get(&key) {
             Some(value) => value.clone(),
-            None => return Err(VoteError::NoSuchProposal),
+            None => return (Err(VoteError::NoSuchProposal), false),
         };
 
         // Check if the caller is the owner of the proposal
         if old_proposal.owner != ic_cdk::caller() {
-            return Err(VoteError::AccessRejected);
+            return (Err(VoteError::AccessRejected), false);
         }
 
-        // Set the proposal as inactive
+        // Set the proposal as inactive and resolve its final outcome
         old_proposal.is_active = false;
+        old_proposal.outcome = old_proposal.evaluate_outcome();
+        let became_accepted = old_proposal.outcome == ProposalOutcome::Accepted;
 
         // Insert the updated proposal into the map and handle the result
-        match p.insert(key, old_proposal) {
+        let result = match p.insert(key, old_proposal) {
             Some(_) => Ok(()),
             None => Err(VoteError::UpdateError),
-        }
-    })
+        };
+
+        (result, became_accepted)
+    });
+
+    // Dispatch the proposal's stored action, if any, now that the stable
+    // map borrow above has been released
+    if result.is_ok() && became_accepted {
+        ic_cdk::spawn(execute_action(key));
+    }
+
+    result
+}
+
+#[ic_cdk::query]
+fn get_outcome(key: u64) -> Option<ProposalOutcome> {
+    PROPOSAL_MAP.with(|p| p.borrow().get(&key).map(|proposal| proposal.outcome))
 }
 
 #[ic_cdk::update]
 fn vote(key: u64, choice: Choice) -> Result<(), VoteError> {
-    PROPOSAL_MAP.with(|p| {
+    require_role(ic_cdk::caller(), &[Role::Admin, Role::Council, Role::Member])?;
+
+    let (result, became_accepted) = PROPOSAL_MAP.with(|p| {
         let mut p = p.borrow_mut();
 
         // Retrieve the proposal or return NoSuchProposal error
         let mut proposal = match p.get(&key) {
             Some(value) => value.clone(),
-            None => return Err(VoteError::NoSuchProposal),
+            None => return (Err(VoteError::NoSuchProposal), false),
         };
 
-        // Check if the caller has already voted or if the proposal is active
+        // Check if the caller has already voted, the voting window has
+        // elapsed, or the proposal is active
         let caller = ic_cdk::caller();
-        if proposal.voted.contains(&caller) {
-            return Err(VoteError::AlreadyVoted);
+        if proposal.voted.iter().any(|(p, _)| *p == caller) {
+            return (Err(VoteError::AlreadyVoted), false);
+        } else if ic_cdk::api::time() >= proposal.deadline() {
+            return (Err(VoteError::VotingClosed), false);
         } else if !proposal.is_active {
-            return Err(VoteError::ProposalIsNotActive);
+            return (Err(VoteError::ProposalIsNotActive), false);
         }
 
+        // Look up the caller's voting power, defaulting to 1 if they were
+        // never assigned an explicit weight
+        let weight = VOTING_POWER_MAP
+            .with(|w| w.borrow().get(&PrincipalKey::from(caller)))
+            .unwrap_or(DEFAULT_VOTING_POWER);
+
         // Update the proposal based on the voting choice
         match choice {
-            Choice::Approve => proposal.approve += 1,
-            Choice::Pass => proposal.pass -= 1,
-            Choice::Reject => proposal.reject += 1,
+            Choice::Approve => proposal.approve += weight,
+            Choice::Pass => proposal.pass += weight,
+            Choice::Reject => proposal.reject += weight,
         }
 
-        // Add the caller to the list of voted participants
-        proposal.voted.push(caller);
+        // Add the caller and the weight they voted with to the list of
+        // voted participants
+        proposal.voted.push((caller, weight));
+
+        // Re-evaluate the outcome now that the tallies have changed
+        proposal.outcome = proposal.evaluate_outcome();
+        let became_accepted = proposal.outcome == ProposalOutcome::Accepted;
 
         // Insert the updated proposal into the map and handle the result
-        match p.insert(key, proposal) {
+        let result = match p.insert(key, proposal) {
             Some(_) => Ok(()),
             None => Err(VoteError::UpdateError),
+        };
+
+        (result, became_accepted)
+    });
+
+    // Dispatch the proposal's stored action, if any, now that the stable
+    // map borrow above has been released
+    if result.is_ok() && became_accepted {
+        ic_cdk::spawn(execute_action(key));
+    }
+
+    result
+}
+
+/// Dispatches a proposal's stored action if it has been `Accepted` and
+/// hasn't already run (or isn't already running), recording whether the
+/// call succeeded. Runs automatically as soon as a proposal is resolved,
+/// with `execute_proposal` available as a manual fallback trigger.
+async fn execute_action(key: u64) {
+    // Claim the execution synchronously, in the same borrow that checks
+    // eligibility, so a second concurrent call to `execute_action` (from
+    // `vote`, `end_proposal`, the heartbeat, or a manual `execute_proposal`
+    // racing the auto-trigger) can't also observe `executed == false` and
+    // fire the action a second time while this call is awaiting `call_raw`.
+    let action = PROPOSAL_MAP.with(|p| {
+        let mut p = p.borrow_mut();
+        let mut proposal = p.get(&key)?;
+
+        if proposal.outcome != ProposalOutcome::Accepted
+            || proposal.execution.executed
+            || proposal.execution.in_flight
+        {
+            return None;
         }
-    })
+
+        let action = proposal.action.clone()?;
+        proposal.execution.in_flight = true;
+        p.insert(key, proposal);
+        Some(action)
+    });
+
+    let action = match action {
+        Some(action) => action,
+        None => return,
+    };
+
+    let outcome = ic_cdk::api::call::call_raw(action.target, &action.method, &action.arg, 0).await;
+
+    PROPOSAL_MAP.with(|p| {
+        let mut p = p.borrow_mut();
+        if let Some(mut proposal) = p.get(&key) {
+            proposal.execution.in_flight = false;
+            match outcome {
+                Ok(_) => {
+                    proposal.execution.executed = true;
+                    proposal.execution.err = None;
+                }
+                Err((_, message)) => proposal.execution.err = Some(message),
+            }
+            p.insert(key, proposal);
+        }
+    });
+}
+
+/// Manually (re)triggers execution of an `Accepted` proposal's action, for
+/// cases where the automatic dispatch from `vote`/`end_proposal` failed.
+#[ic_cdk::update]
+async fn execute_proposal(key: u64) -> Result<(), VoteError> {
+    let proposal = PROPOSAL_MAP
+        .with(|p| p.borrow().get(&key))
+        .ok_or(VoteError::NoSuchProposal)?;
+
+    if proposal.outcome != ProposalOutcome::Accepted {
+        return Err(VoteError::ProposalIsNotActive);
+    }
+
+    execute_action(key).await;
+    Ok(())
+}
+
+/// Assigns a principal's voting weight. Gated to `Admin` members.
+#[ic_cdk::update]
+fn set_voting_power(principal: candid::Principal, weight: u64) -> Result<(), VoteError> {
+    require_role(ic_cdk::caller(), &[Role::Admin])?;
+
+    VOTING_POWER_MAP.with(|w| w.borrow_mut().insert(PrincipalKey::from(principal), weight));
+    Ok(())
+}
+
+/// Registers a principal with a role. Gated to `Admin` members.
+#[ic_cdk::update]
+fn add_member(principal: candid::Principal, role: Role) -> Result<(), VoteError> {
+    require_role(ic_cdk::caller(), &[Role::Admin])?;
+
+    MEMBERS_MAP.with(|m| m.borrow_mut().insert(PrincipalKey::from(principal), role));
+    Ok(())
+}
+
+/// Removes a principal from the membership registry. Gated to `Admin`
+/// members.
+#[ic_cdk::update]
+fn remove_member(principal: candid::Principal) -> Result<(), VoteError> {
+    require_role(ic_cdk::caller(), &[Role::Admin])?;
+
+    MEMBERS_MAP.with(|m| m.borrow_mut().remove(&PrincipalKey::from(principal)));
+    Ok(())
+}
+
+#[ic_cdk::query]
+fn list_members() -> Vec<(candid::Principal, Role)> {
+    MEMBERS_MAP.with(|m| m.borrow().iter().map(|(key, role)| (key.0, role)).collect())
+}
+
+/// Sweeps proposals whose voting window has elapsed: flips `is_active` to
+/// false, resolves the final `outcome`, and dispatches an `Accepted`
+/// proposal's action, so stale proposals are closed and, where applicable,
+/// executed without an owner calling `end_proposal` explicitly.
+#[ic_cdk::heartbeat]
+fn close_expired_proposals() {
+    let now = ic_cdk::api::time();
+
+    let expired: Vec<(u64, Proposal)> = PROPOSAL_MAP.with(|p| {
+        p.borrow()
+            .iter()
+            .filter(|(_, proposal)| proposal.is_active && now >= proposal.deadline())
+            .collect()
+    });
+
+    if expired.is_empty() {
+        return;
+    }
+
+    // Mirror `end_proposal`: closing a proposal also resolves its final
+    // outcome, and an `Accepted` outcome dispatches its stored action,
+    // rather than leaving both waiting on someone to call `end_proposal`
+    // by hand.
+    let newly_accepted: Vec<u64> = PROPOSAL_MAP.with(|p| {
+        let mut p = p.borrow_mut();
+        let mut newly_accepted = Vec::new();
+        for (key, mut proposal) in expired {
+            proposal.is_active = false;
+            proposal.outcome = proposal.evaluate_outcome();
+            if proposal.outcome == ProposalOutcome::Accepted {
+                newly_accepted.push(key);
+            }
+            p.insert(key, proposal);
+        }
+        newly_accepted
+    });
+
+    for key in newly_accepted {
+        ic_cdk::spawn(execute_action(key));
+    }
 }